@@ -213,6 +213,63 @@ where
         self.len = Lazy::new(0);
     }
 
+    /// Takes the element at `index` out of the heap, leaving a logical hole
+    /// behind without affecting `len`.
+    ///
+    /// Returns `None` if `index` is out of bounds or the slot is already
+    /// empty.
+    pub fn take(&mut self, index: u32) -> Option<T> {
+        self.get_child_mut(index)?.child.take()
+    }
+
+    /// Places `value` into the slot at `index`, filling a hole previously
+    /// left by [`ChildrenVector::take`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn place(&mut self, index: u32, value: T) {
+        assert!(index < self.len(), "index is out of bounds");
+        let info = self.get_child_mut(index).expect("index must exist");
+        *info.child = Some(value);
+    }
+
+    /// Truncates the heap to `new_len` elements, dropping any elements at or
+    /// beyond that index and freeing their storage cells.
+    ///
+    /// Does nothing if `new_len` is greater than or equal to the current
+    /// length.
+    ///
+    /// # Note
+    ///
+    /// This clears the dropped slots directly and then shrinks the backing
+    /// storage vector to exactly the number of `Children` cells `new_len`
+    /// requires, computed from the index mapping. It deliberately does not
+    /// go through [`ChildrenVector::pop`]'s one-at-a-time `child_count`
+    /// check, which infers whether a cell is free from its *current*
+    /// occupancy: that only works if cells are vacated strictly back to
+    /// front, an assumption callers that use `take`/`place` to compact
+    /// (e.g. `retain`) don't satisfy, and which silently drops the wrong
+    /// cell when violated.
+    pub fn truncate(&mut self, new_len: u32) {
+        let len = self.len();
+        if new_len >= len {
+            return
+        }
+        for index in new_len..len {
+            self.take(index);
+        }
+        let needed_cells = if new_len == 0 {
+            0
+        } else {
+            children::get_children_storage_index(new_len - 1) + 1
+        };
+        while self.children.len() > needed_cells {
+            self.children.pop();
+        }
+        *self.len = new_len;
+    }
+
     /// Appends an element to the back of the heap.
     pub fn push(&mut self, value: T) {
         assert!(
@@ -147,9 +147,31 @@ where
 
     /// Take an element at `pos` and move it down the heap, while its children
     /// are smaller.
-    fn sift_down(&mut self, mut pos: u32) {
+    ///
+    /// # Note
+    ///
+    /// Uses the "hole" technique: the element is taken out up front, leaving
+    /// a logical hole, and the greater child is moved into the hole until
+    /// the right spot is found, for one storage write per level instead of
+    /// the two a `swap`-based sift would need.
+    fn sift_down(&mut self, pos: u32) {
         let end = self.len();
-        let mut child = 2 * pos + 1;
+        self.sift_down_range(pos, end)
+    }
+
+    /// Like [`BinaryHeap::sift_down`], but restricted to the sub-range
+    /// `[0, end)` instead of the full heap.
+    ///
+    /// Used by [`BinaryHeap::into_sorted_vec`] to sift within the
+    /// still-unsorted prefix during an in-place heapsort, without disturbing
+    /// the already-sorted suffix.
+    fn sift_down_range(&mut self, pos: u32, end: u32) {
+        let mut hole = pos;
+        let elem = match self.elements.take(hole) {
+            Some(elem) => elem,
+            None => return,
+        };
+        let mut child = 2 * hole + 1;
         while child < end {
             let right = child + 1;
             // compare with the greater of the two children
@@ -157,13 +179,15 @@ where
                 child = right;
             }
             // if we are already in order, stop.
-            if self.elements.get(pos) >= self.elements.get(child) {
+            if &elem >= self.elements.get(child).expect("child must exist") {
                 break
             }
-            self.elements.swap(child, pos);
-            pos = child;
-            child = 2 * pos + 1;
+            let moved = self.elements.take(child).expect("child must exist");
+            self.elements.place(hole, moved);
+            hole = child;
+            child = 2 * hole + 1;
         }
+        self.elements.place(hole, elem);
     }
 
     /// Pops greatest element from the heap and returns it
@@ -176,6 +200,68 @@ where
         elem
     }
 
+    /// Consumes the heap and returns a vector in sorted (ascending) order.
+    ///
+    /// # Note
+    ///
+    /// Classic in-place heapsort: the root is repeatedly swapped with the
+    /// current last element of the shrinking heap range and sifted down over
+    /// what remains, leaving the buffer in ascending order.
+    pub fn into_sorted_vec(mut self) -> StorageVec<T> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.elements.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+        let mut sorted = StorageVec::new();
+        for i in 0..self.len() {
+            if let Some(value) = self.elements.take(i) {
+                sorted.push(value);
+            }
+        }
+        // The values were only taken out of their in-memory `Option` slots
+        // above; the underlying storage cells are still allocated. Truncate
+        // them away now instead of leaving them as orphaned storage.
+        self.elements.truncate(0);
+        sorted
+    }
+
+    /// Returns a draining iterator over the elements of the heap in
+    /// descending (sorted) order.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`BinaryHeap::into_sorted_vec`] this does not sort the whole
+    /// heap up front; it lazily `pop`s one element at a time, so callers
+    /// that only need the top `k` elements can stop early via
+    /// `Iterator::take` without paying for a full sort.
+    pub fn drain_sorted(&mut self) -> DrainSorted<T> {
+        DrainSorted { heap: self }
+    }
+
+    /// Rebuilds the heap invariant in `O(n)`.
+    ///
+    /// # Note
+    ///
+    /// Used after bulk-loading elements (e.g. via [`FromIterator`] or
+    /// [`BinaryHeap::append`]) instead of sifting up every element one by
+    /// one, which would cost `O(n log n)`.
+    fn rebuild(&mut self) {
+        let len = self.len();
+        if len < 2 {
+            return
+        }
+        let mut pos = len / 2 - 1;
+        loop {
+            self.sift_down(pos);
+            if pos == 0 {
+                break
+            }
+            pos -= 1;
+        }
+    }
+
     /// Removes all elements from this heap.
     ///
     /// # Note
@@ -186,6 +272,114 @@ where
     pub fn clear(&mut self) {
         self.elements.clear()
     }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// # Note
+    ///
+    /// If `other` is small relative to `self` its elements are pushed in one
+    /// by one, since that touches fewer cells than rebuilding the bigger
+    /// combined heap. Otherwise all elements are moved into storage in bulk
+    /// and a single `O(n)` `rebuild` restores the heap invariant.
+    pub fn append(&mut self, other: &mut BinaryHeap<T>) {
+        if other.is_empty() {
+            return
+        }
+        if other.len() <= self.len() / 4 {
+            while let Some(value) = other.pop() {
+                self.push(value);
+            }
+            return
+        }
+        for i in 0..other.len() {
+            if let Some(value) = other.elements.take(i) {
+                self.elements.push(value);
+            }
+        }
+        other.elements.clear();
+        self.rebuild();
+    }
+
+    /// Retains only the elements specified by `f`, in a single pass.
+    ///
+    /// # Note
+    ///
+    /// Compacts the retained elements towards the front of storage using the
+    /// same take/place helpers as the hole-based sift, truncates away the
+    /// freed tail, then restores the heap invariant with an `O(n)`
+    /// `rebuild`. This prunes the heap in one traversal instead of
+    /// repeatedly popping and re-pushing, which would otherwise thrash
+    /// storage.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let mut write = 0;
+        for read in 0..len {
+            let value = self
+                .elements
+                .take(read)
+                .expect("element must exist below len");
+            if f(&value) {
+                self.elements.place(write, value);
+                write += 1;
+            }
+        }
+        self.elements.truncate(write);
+        self.rebuild();
+    }
+
+    /// Pushes `item` onto the heap, then pops and returns the greatest
+    /// element of the resulting heap, in a single sift pass.
+    ///
+    /// # Note
+    ///
+    /// If `item` is already greatest it's returned unchanged without
+    /// touching storage. Otherwise it's written straight into the root slot
+    /// and a single `sift_down` restores the heap, avoiding the separate
+    /// `sift_up` + `sift_down` a naive `push` then `pop` would incur.
+    pub fn push_pop(&mut self, mut item: T) -> T {
+        let replace = match self.peek() {
+            Some(root) => item < *root,
+            None => false,
+        };
+        if !replace {
+            return item
+        }
+        let root = self
+            .elements
+            .first_mut()
+            .expect("heap is non-empty, checked via peek above");
+        core::mem::swap(&mut item, root);
+        self.sift_down(0);
+        item
+    }
+
+    /// Replaces the greatest element of the heap with `item` and returns the
+    /// old greatest element, in a single sift pass.
+    ///
+    /// Returns `None` if the heap was empty, in which case `item` is simply
+    /// pushed.
+    ///
+    /// # Note
+    ///
+    /// Combines an insert and an extract into one `sift_down`, avoiding the
+    /// separate `sift_up` + `sift_down` a naive `push` then `pop` would
+    /// incur.
+    pub fn replace(&mut self, item: T) -> Option<T> {
+        if self.is_empty() {
+            self.push(item);
+            return None
+        }
+        let root = self
+            .elements
+            .first_mut()
+            .expect("heap is non-empty, checked above");
+        let old = core::mem::replace(root, item);
+        self.sift_down(0);
+        Some(old)
+    }
 }
 
 impl<T> BinaryHeap<T>
@@ -194,15 +388,26 @@ where
 {
     /// Take an element at `pos` and move it up the heap, while its parent is
     /// larger.
-    fn sift_up(&mut self, mut pos: u32) {
-        while pos > 0 {
-            let parent = (pos - 1) / 2;
-            if self.elements.get(pos) <= self.elements.get(parent) {
+    ///
+    /// # Note
+    ///
+    /// See [`BinaryHeap::sift_down`] for why this uses the "hole" technique.
+    fn sift_up(&mut self, pos: u32) {
+        let mut hole = pos;
+        let elem = match self.elements.take(hole) {
+            Some(elem) => elem,
+            None => return,
+        };
+        while hole > 0 {
+            let parent = (hole - 1) / 2;
+            if &elem <= self.elements.get(parent).expect("parent must exist") {
                 break
             }
-            self.elements.swap(parent, pos);
-            pos = parent;
+            let moved = self.elements.take(parent).expect("parent must exist");
+            self.elements.place(hole, moved);
+            hole = parent;
         }
+        self.elements.place(hole, elem);
     }
 
     /// Pushes the given element to the binary heap.
@@ -213,6 +418,42 @@ where
     }
 }
 
+impl<T> core::iter::FromIterator<T> for BinaryHeap<T>
+where
+    T: PackedLayout + Ord,
+{
+    /// Creates a storage heap from an iterator, in `O(n)`.
+    ///
+    /// # Note
+    ///
+    /// Elements are appended to storage without sifting, then the heap
+    /// invariant is restored with a single bottom-up `rebuild` — cheaper
+    /// than inserting one by one via [`BinaryHeap::push`].
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+impl<T> Extend<T> for BinaryHeap<T>
+where
+    T: PackedLayout + Ord,
+{
+    /// Extends the heap with the contents of an iterator, in `O(n)`.
+    ///
+    /// # Note
+    ///
+    /// See [`BinaryHeap::from_iter`] for why this is cheaper than repeated
+    /// [`BinaryHeap::push`] calls.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.elements.push(value);
+        }
+        self.rebuild();
+    }
+}
+
 /// Structure wrapping a mutable reference to the greatest item on a
 /// [`BinaryHeap`].
 ///
@@ -424,3 +665,31 @@ where
         self.get_mut(cur).expect("access is out of bounds").into()
     }
 }
+
+/// A draining iterator over the elements of a `BinaryHeap`, in descending
+/// (sorted) order.
+///
+/// This `struct` is created by the [`BinaryHeap::drain_sorted`] method.
+pub struct DrainSorted<'a, T>
+where
+    T: PackedLayout + Ord,
+{
+    /// The heap being drained.
+    heap: &'a mut BinaryHeap<T>,
+}
+
+impl<'a, T> Iterator for DrainSorted<'a, T>
+where
+    T: PackedLayout + Ord,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.heap.len() as usize;
+        (remaining, Some(remaining))
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2019-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn from_iter_builds_valid_heap() {
+    let heap: BinaryHeap<i32> = vec![5, 1, 9, 3, 7].into_iter().collect();
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.peek(), Some(&9));
+}
+
+#[test]
+fn extend_rebuilds_heap_invariant() {
+    let mut heap = BinaryHeap::new();
+    heap.push(2);
+    heap.extend(vec![10, 1, 8]);
+    assert_eq!(heap.len(), 4);
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, vec![10, 8, 2, 1]);
+}
+
+#[test]
+fn sift_down_handles_duplicates() {
+    let mut heap = BinaryHeap::new();
+    for value in [5, 5, 5, 5, 5] {
+        heap.push(value);
+    }
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, vec![5, 5, 5, 5, 5]);
+}
+
+#[test]
+fn sift_up_maintains_order_across_many_pushes() {
+    let values = [9, 3, 7, 1, 8, 2, 6, 4, 0, 5];
+    let mut heap = BinaryHeap::new();
+    for value in values {
+        heap.push(value);
+    }
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    let mut expected = values.to_vec();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(popped, expected);
+}
+
+#[test]
+fn sift_on_single_element_heap_is_noop() {
+    let mut heap = BinaryHeap::new();
+    heap.push(1);
+    assert_eq!(heap.pop(), Some(1));
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn pop_on_empty_heap_is_none() {
+    let mut heap = <BinaryHeap<i32>>::new();
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn into_sorted_vec_is_ascending() {
+    let heap: BinaryHeap<i32> = vec![5, 1, 9, 3, 7].into_iter().collect();
+    let sorted = heap.into_sorted_vec();
+    let values: Vec<i32> = sorted.iter().copied().collect();
+    assert_eq!(values, vec![1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn into_sorted_vec_drains_all_sizes() {
+    // Regression test: into_sorted_vec's final truncate(0) call relied on
+    // ChildrenVector::truncate, which used to corrupt the heap instead of
+    // just failing to free storage (see
+    // `retain_does_not_corrupt_surviving_elements`); sweep a range of sizes to make sure draining still produces
+    // the right values now that truncate is fixed.
+    for len in 0..12 {
+        let heap: BinaryHeap<i32> = (0..len).rev().collect();
+        let sorted: Vec<i32> = heap.into_sorted_vec().iter().copied().collect();
+        let expected: Vec<i32> = (0..len).collect();
+        assert_eq!(sorted, expected, "len={}", len);
+    }
+}
+
+#[test]
+fn drain_sorted_yields_descending_order() {
+    let mut heap: BinaryHeap<i32> = vec![5, 1, 9, 3, 7].into_iter().collect();
+    let drained: Vec<i32> = heap.drain_sorted().collect();
+    assert_eq!(drained, vec![9, 7, 5, 3, 1]);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn drain_sorted_can_be_taken_partially() {
+    let mut heap: BinaryHeap<i32> = vec![5, 1, 9, 3, 7].into_iter().collect();
+    let top_two: Vec<i32> = heap.drain_sorted().take(2).collect();
+    assert_eq!(top_two, vec![9, 7]);
+}
+
+#[test]
+fn append_uses_incremental_push_when_other_is_small() {
+    let mut a: BinaryHeap<i32> = (0..20).collect();
+    let mut b: BinaryHeap<i32> = vec![100].into_iter().collect();
+    a.append(&mut b);
+    assert!(b.is_empty());
+    assert_eq!(a.len(), 21);
+    assert_eq!(a.pop(), Some(100));
+}
+
+#[test]
+fn append_rebuilds_when_other_is_large() {
+    let mut a: BinaryHeap<i32> = vec![1].into_iter().collect();
+    let mut b: BinaryHeap<i32> = (0..20).collect();
+    a.append(&mut b);
+    assert!(b.is_empty());
+    assert_eq!(a.len(), 21);
+    assert_eq!(a.pop(), Some(19));
+}
+
+#[test]
+fn append_with_empty_other_is_noop() {
+    let mut a: BinaryHeap<i32> = vec![3, 1].into_iter().collect();
+    let mut b = BinaryHeap::new();
+    a.append(&mut b);
+    assert_eq!(a.len(), 2);
+}
+
+#[test]
+fn retain_drops_elements_not_matching_predicate() {
+    let mut heap: BinaryHeap<i32> = (0..10).collect();
+    heap.retain(|&value| value % 2 == 0);
+    assert_eq!(heap.len(), 5);
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, vec![8, 6, 4, 2, 0]);
+}
+
+#[test]
+fn retain_keeping_everything_is_noop() {
+    let mut heap: BinaryHeap<i32> = vec![3, 1, 2].into_iter().collect();
+    heap.retain(|_| true);
+    assert_eq!(heap.len(), 3);
+}
+
+#[test]
+fn retain_on_empty_heap() {
+    let mut heap = <BinaryHeap<i32>>::new();
+    heap.retain(|_| true);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn retain_does_not_corrupt_surviving_elements() {
+    // Regression test: an earlier `ChildrenVector::truncate` inferred which
+    // storage cells were free from their *current* occupancy, which broke
+    // once `retain`'s compaction could vacate a cell's sibling slot out of
+    // back-to-front order, silently dropping a surviving element instead of
+    // just leaking storage.
+    let mut heap: BinaryHeap<i32> = vec![0, 1, 2].into_iter().collect();
+    heap.retain(|&value| value != 2);
+    assert_eq!(heap.len(), 2);
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, vec![1, 0]);
+}
+
+#[test]
+fn retain_preserves_values_across_sizes_and_parities() {
+    for len in 0..12 {
+        for keep_even in [true, false] {
+            let mut heap: BinaryHeap<i32> = (0..len).collect();
+            let mut expected: Vec<i32> = (0..len)
+                .filter(|value| (value % 2 == 0) == keep_even)
+                .collect();
+            heap.retain(|&value| (value % 2 == 0) == keep_even);
+            let mut popped = Vec::new();
+            while let Some(value) = heap.pop() {
+                popped.push(value);
+            }
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            assert_eq!(popped, expected, "len={}, keep_even={}", len, keep_even);
+        }
+    }
+}
+
+#[test]
+fn push_pop_returns_new_item_when_not_greater() {
+    let mut heap: BinaryHeap<i32> = vec![10, 5, 7].into_iter().collect();
+    let popped = heap.push_pop(20);
+    assert_eq!(popped, 20);
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some(&10));
+}
+
+#[test]
+fn push_pop_swaps_in_smaller_item() {
+    let mut heap: BinaryHeap<i32> = vec![10, 5, 7].into_iter().collect();
+    let popped = heap.push_pop(1);
+    assert_eq!(popped, 10);
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some(&7));
+}
+
+#[test]
+fn push_pop_on_empty_heap_returns_item_unchanged() {
+    let mut heap = BinaryHeap::new();
+    assert_eq!(heap.push_pop(5), 5);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn replace_returns_previous_root() {
+    let mut heap: BinaryHeap<i32> = vec![10, 5, 7].into_iter().collect();
+    let previous = heap.replace(3);
+    assert_eq!(previous, Some(10));
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some(&7));
+}
+
+#[test]
+fn replace_on_empty_heap_pushes_and_returns_none() {
+    let mut heap = BinaryHeap::new();
+    assert_eq!(heap.replace(9), None);
+    assert_eq!(heap.peek(), Some(&9));
+}